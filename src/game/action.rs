@@ -0,0 +1,56 @@
+//! Ported from https://github.com/software-challenge/backend/blob/be88340f619892fe70c4cbd45e131d5445e883c7/plugin/src/main/kotlin/sc/plugin2024/Action.kt
+
+use crate::util::{Element, Error, Result};
+
+use super::CubeDir;
+
+/// A single action within a [`Move`](super::Move).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Action {
+    /// Changes the ship's speed by the given delta.
+    Accelerate(i32),
+    /// Turns the ship to face the given direction.
+    Turn(CubeDir),
+    /// Moves the ship forward by the given number of fields.
+    Advance(i32),
+}
+
+impl Action {
+    /// An action that changes speed by `delta`.
+    pub fn accelerate(delta: i32) -> Self {
+        Self::Accelerate(delta)
+    }
+
+    /// An action that turns the ship to face `direction`.
+    pub fn turn(direction: CubeDir) -> Self {
+        Self::Turn(direction)
+    }
+
+    /// An action that advances the ship by `distance` fields.
+    pub fn advance(distance: i32) -> Self {
+        Self::Advance(distance)
+    }
+}
+
+impl TryFrom<&Element> for Action {
+    type Error = Error;
+
+    fn try_from(elem: &Element) -> Result<Self> {
+        match elem.name() {
+            "acceleration" => Ok(Self::Accelerate(elem.attribute("acc")?.parse()?)),
+            "turn" => Ok(Self::Turn(elem.attribute("direction")?.parse()?)),
+            "advance" => Ok(Self::Advance(elem.attribute("distance")?.parse()?)),
+            other => Err(Error::UnknownVariant(format!("Unknown action type {other}"))),
+        }
+    }
+}
+
+impl From<Action> for Element {
+    fn from(action: Action) -> Self {
+        match action {
+            Action::Accelerate(delta) => Element::new("acceleration").attribute("acc", delta).build(),
+            Action::Turn(direction) => Element::new("turn").attribute("direction", direction).build(),
+            Action::Advance(distance) => Element::new("advance").attribute("distance", distance).build(),
+        }
+    }
+}
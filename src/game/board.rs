@@ -1,24 +1,364 @@
 //! Ported from https://github.com/software-challenge/backend/blob/be88340f619892fe70c4cbd45e131d5445e883c7/plugin/src/main/kotlin/sc/plugin2024/Board.kt
 
-use crate::util::{Element, Error, Result};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
 
-use super::{CubeDir, Segment};
+use crate::util::{Element, Error, Result, Vec2};
+
+use super::{Action, CubeDir, CubeVec, Field, Move, Segment, ShipState};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Board {
     segments: Vec<Segment>,
+    /// The direction the next, not-yet-revealed segment will be placed in once it arrives.
+    ///
+    /// Every already-known segment carries its own [`Segment::direction`], which is what
+    /// [`stitch_fields`] actually folds bends on; this field only matters for extending the map
+    /// beyond the segments we currently know about, so it is intentionally unused here.
     next_direction: CubeDir,
+    fields: HashMap<CubeVec, Field>,
 }
 
 impl TryFrom<&Element> for Board {
     type Error = Error;
 
     fn try_from(elem: &Element) -> Result<Self> {
-        Ok(Self {
-            segments: elem.childs_by_name("segment")
-                .map(Segment::try_from)
-                .collect::<Result<Vec<Segment>>>()?,
-            next_direction: elem.attribute("nextDirection")?.parse()?,
-        })
+        let segments = elem.childs_by_name("segment")
+            .map(Segment::try_from)
+            .collect::<Result<Vec<Segment>>>()?;
+        let next_direction = elem.attribute("nextDirection")?.parse()?;
+        let fields = stitch_fields(&segments);
+
+        Ok(Self { segments, next_direction, fields })
+    }
+}
+
+impl Board {
+    /// Looks up the field at the given absolute position.
+    ///
+    /// Returns `None` if `pos` does not lie within any known segment.
+    pub fn field_at(&self, pos: CubeVec) -> Option<Field> {
+        self.fields.get(&pos).copied()
+    }
+
+    /// Iterates over every known field together with its absolute position.
+    pub fn iter_fields(&self) -> impl Iterator<Item = (CubeVec, Field)> + '_ {
+        self.fields.iter().map(|(&pos, &field)| (pos, field))
+    }
+
+    /// Finds the shortest path of passable water fields from `start` to `goal` using A* with
+    /// the admissible hex-distance heuristic, or `None` if `goal` is unreachable.
+    pub fn find_path(&self, start: CubeVec, goal: CubeVec) -> Option<Vec<CubeVec>> {
+        let heuristic = |pos: CubeVec| {
+            let diff = goal - pos;
+            diff.r().abs().max(diff.q().abs()).max(diff.s().abs())
+        };
+
+        let mut open = BinaryHeap::new();
+        open.push(OpenEntry { f: heuristic(start), pos: start });
+
+        let mut came_from = HashMap::new();
+        let mut g_score = HashMap::new();
+        g_score.insert(start, 0);
+
+        while let Some(OpenEntry { pos, .. }) = open.pop() {
+            if pos == goal {
+                return Some(reconstruct_path(&came_from, pos));
+            }
+
+            let g = g_score[&pos];
+            for neighbor in pos.hex_neighbors() {
+                if !matches!(self.field_at(neighbor), Some(field) if is_passable(field)) {
+                    continue;
+                }
+
+                let tentative_g = g + 1;
+                if tentative_g < *g_score.get(&neighbor).unwrap_or(&i32::MAX) {
+                    came_from.insert(neighbor, pos);
+                    g_score.insert(neighbor, tentative_g);
+                    open.push(OpenEntry { f: tentative_g + heuristic(neighbor), pos: neighbor });
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Enumerates the legal moves available to a ship with the given position, facing, speed
+    /// and coal reserve, stopping advances short of any field listed in `occupied`.
+    pub fn possible_moves(&self, ship: &ShipState, occupied: &[CubeVec]) -> Vec<Move> {
+        let mut moves = Vec::new();
+
+        for direction in CubeDir::ALL {
+            let turn_cost = ship.direction.turn_count_to(direction).abs();
+            if turn_cost > ship.coal {
+                continue;
+            }
+            let remaining_coal = ship.coal - turn_cost;
+            let turn = (direction != ship.direction).then(|| Action::turn(direction));
+
+            for speed in 1..=6 {
+                let accel_cost = (speed - ship.speed).abs();
+                if accel_cost > remaining_coal {
+                    continue;
+                }
+                let accelerate = (speed != ship.speed).then(|| Action::accelerate(speed - ship.speed));
+
+                for distance in self.reachable_distances(ship.position, direction, speed, occupied) {
+                    let mut actions = Vec::new();
+                    actions.extend(turn.clone());
+                    actions.extend(accelerate.clone());
+                    actions.push(Action::advance(distance));
+                    moves.push(Move { actions });
+                }
+            }
+        }
+
+        moves
+    }
+
+    /// The advance distances reachable from `position` when travelling in `direction` at
+    /// `speed`, stopping short of impassable fields, the edge of the board, or any field in
+    /// `occupied`.
+    fn reachable_distances(&self, position: CubeVec, direction: CubeDir, speed: i32, occupied: &[CubeVec]) -> Vec<i32> {
+        let mut distances = Vec::new();
+        let mut current = position;
+
+        for step in 1..=speed {
+            current += direction;
+            if occupied.contains(&current) {
+                break;
+            }
+            match self.field_at(current) {
+                Some(field) if is_passable(field) => distances.push(step),
+                _ => break,
+            }
+        }
+
+        distances
+    }
+}
+
+/// Folds the segments into an absolute-coordinate index, starting from the first segment's
+/// center and advancing by each segment's own direction vector before placing the next one.
+fn stitch_fields(segments: &[Segment]) -> HashMap<CubeVec, Field> {
+    let mut fields = HashMap::new();
+    let Some(first) = segments.first() else {
+        return fields;
+    };
+
+    let mut center = first.center();
+    for segment in segments {
+        let turns = segment.direction().turns();
+        for (x, column) in segment.fields().iter().enumerate() {
+            for (y, field) in column.iter().enumerate() {
+                let local = CubeVec::from(Vec2::new(x as i32, y as i32));
+                fields.insert(center + local.rotated_by(turns), *field);
+            }
+        }
+        center += CubeVec::from(segment.direction()) * segment.fields().len() as i32;
+    }
+
+    fields
+}
+
+/// Whether a ship can sail through the given field.
+fn is_passable(field: Field) -> bool {
+    !matches!(field, Field::Island | Field::Sandbank)
+}
+
+fn reconstruct_path(came_from: &HashMap<CubeVec, CubeVec>, mut current: CubeVec) -> Vec<CubeVec> {
+    let mut path = vec![current];
+    while let Some(&prev) = came_from.get(&current) {
+        path.push(prev);
+        current = prev;
+    }
+    path.reverse();
+    path
+}
+
+/// An entry in the A* open set, ordered by ascending `f` score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct OpenEntry {
+    f: i32,
+    pos: CubeVec,
+}
+
+impl Ord for OpenEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.f.cmp(&self.f)
+    }
+}
+
+impl PartialOrd for OpenEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board_with_fields(fields: HashMap<CubeVec, Field>) -> Board {
+        Board { segments: Vec::new(), next_direction: CubeDir::Right, fields }
+    }
+
+    #[test]
+    fn test_find_path_returns_singleton_when_start_equals_goal() {
+        let mut fields = HashMap::new();
+        fields.insert(CubeVec::ZERO, Field::Water);
+        let board = board_with_fields(fields);
+
+        assert_eq!(board.find_path(CubeVec::ZERO, CubeVec::ZERO), Some(vec![CubeVec::ZERO]));
+    }
+
+    #[test]
+    fn test_find_path_returns_none_when_goal_is_unreachable() {
+        let goal = CubeVec::ZERO;
+        let start = CubeVec::from(CubeDir::Right) * 3;
+
+        let mut fields = HashMap::new();
+        fields.insert(goal, Field::Water);
+        for neighbor in goal.hex_neighbors() {
+            fields.insert(neighbor, Field::Island);
+        }
+        fields.insert(start, Field::Water);
+
+        let board = board_with_fields(fields);
+        assert_eq!(board.find_path(start, goal), None);
+    }
+
+    #[test]
+    fn test_find_path_routes_around_an_obstacle() {
+        let p0 = CubeVec::ZERO;
+        let p1 = p0 + CubeDir::Right;
+        let p2 = p1 + CubeDir::Right; // an island, blocking the straight line
+        let d1 = p1 + CubeDir::DownRight;
+        let d2 = d1 + CubeDir::Right;
+        let p3 = d2 + CubeDir::UpRight; // rejoins the straight line, bypassing `p2`
+        let p4 = p3 + CubeDir::Right;
+        assert_eq!(p3, p0 + CubeDir::Right + CubeDir::Right + CubeDir::Right);
+
+        let mut fields = HashMap::new();
+        for &pos in &[p0, p1, p3, p4, d1, d2] {
+            fields.insert(pos, Field::Water);
+        }
+        fields.insert(p2, Field::Island);
+        let board = board_with_fields(fields);
+
+        assert_eq!(board.find_path(p0, p4), Some(vec![p0, p1, d1, d2, p3, p4]));
+    }
+
+    fn open_water(radius: i32) -> HashMap<CubeVec, Field> {
+        CubeVec::ZERO.range(radius).map(|pos| (pos, Field::Water)).collect()
+    }
+
+    #[test]
+    fn test_possible_moves_excludes_turns_beyond_available_coal() {
+        let board = board_with_fields(open_water(6));
+        let ship = ShipState { position: CubeVec::ZERO, direction: CubeDir::Right, speed: 1, coal: 0 };
+
+        let moves = board.possible_moves(&ship, &[]);
+
+        assert!(moves.iter().all(|m| !m.actions.iter().any(|a| matches!(a, Action::Turn(_)))));
+        assert!(moves.iter().any(|m| m.actions == vec![Action::advance(1)]));
+    }
+
+    #[test]
+    fn test_possible_moves_excludes_acceleration_beyond_coal_left_after_turning() {
+        let board = board_with_fields(open_water(6));
+        let ship = ShipState { position: CubeVec::ZERO, direction: CubeDir::Right, speed: 3, coal: 1 };
+
+        let moves = board.possible_moves(&ship, &[]);
+
+        // A turn that spends the only available coal must never be combined with an
+        // acceleration, since that would cost more coal than the ship has.
+        assert!(moves.iter().all(|m| {
+            let turned = m.actions.iter().any(|a| matches!(a, Action::Turn(_)));
+            let accelerated = m.actions.iter().any(|a| matches!(a, Action::Accelerate(_)));
+            !(turned && accelerated)
+        }));
+        // But a lone turn (no speed change) must still be allowed.
+        assert!(moves.iter().any(|m| matches!(
+            m.actions.as_slice(),
+            [Action::Turn(CubeDir::DownRight), Action::Advance(_)]
+        )));
+    }
+
+    #[test]
+    fn test_possible_moves_stops_advance_short_of_an_occupied_field() {
+        let right = CubeVec::from(CubeDir::Right);
+        let fields = (0..=5).map(|i| (right * i, Field::Water)).collect();
+        let board = board_with_fields(fields);
+        let ship = ShipState { position: CubeVec::ZERO, direction: CubeDir::Right, speed: 6, coal: 0 };
+        let occupied = [right * 3];
+
+        let moves = board.possible_moves(&ship, &occupied);
+        let max_distance = moves.iter()
+            .filter_map(|m| match m.actions.as_slice() { [Action::Advance(d)] => Some(*d), _ => None })
+            .max();
+
+        assert_eq!(max_distance, Some(2));
+    }
+
+    #[test]
+    fn test_possible_moves_stops_advance_at_the_edge_of_the_known_map() {
+        let right = CubeVec::from(CubeDir::Right);
+        let fields = (0..=2).map(|i| (right * i, Field::Water)).collect();
+        let board = board_with_fields(fields);
+        let ship = ShipState { position: CubeVec::ZERO, direction: CubeDir::Right, speed: 6, coal: 0 };
+
+        let moves = board.possible_moves(&ship, &[]);
+        let max_distance = moves.iter()
+            .filter_map(|m| match m.actions.as_slice() { [Action::Advance(d)] => Some(*d), _ => None })
+            .max();
+
+        assert_eq!(max_distance, Some(2));
+    }
+
+    #[test]
+    fn test_stitch_fields_folds_multiple_segments_including_a_bend() {
+        let segment1 = Segment::new(
+            CubeDir::Right,
+            CubeVec::ZERO,
+            vec![vec![Field::Water, Field::Water], vec![Field::Water, Field::Water]],
+        );
+        // `next_direction` is irrelevant here: it is `segment2`'s own `direction()` that bends
+        // the layout, proving the field is (intentionally) not needed to fold known segments.
+        let segment2 = Segment::new(
+            CubeDir::DownRight,
+            CubeVec::ZERO,
+            vec![vec![Field::Water, Field::Island]],
+        );
+
+        let board = Board {
+            segments: vec![segment1.clone(), segment2.clone()],
+            next_direction: CubeDir::Left,
+            fields: stitch_fields(&[segment1.clone(), segment2.clone()]),
+        };
+
+        // Segment 1 is unrotated and starts at its own center.
+        for (x, column) in segment1.fields().iter().enumerate() {
+            for (y, field) in column.iter().enumerate() {
+                let local = CubeVec::from(Vec2::new(x as i32, y as i32));
+                assert_eq!(board.field_at(segment1.center() + local), Some(*field));
+            }
+        }
+
+        // Segment 2 starts where segment 1 left off and is rotated by its own direction's turn
+        // count -- this is the bend.
+        let second_origin = segment1.center()
+            + CubeVec::from(segment1.direction()) * segment1.fields().len() as i32;
+        let turns = segment2.direction().turns();
+        for (x, column) in segment2.fields().iter().enumerate() {
+            for (y, field) in column.iter().enumerate() {
+                let local = CubeVec::from(Vec2::new(x as i32, y as i32)).rotated_by(turns);
+                assert_eq!(board.field_at(second_origin + local), Some(*field));
+            }
+        }
+
+        assert_eq!(board.iter_fields().count(), 6);
+        assert_eq!(board.field_at(CubeVec::ZERO + CubeDir::Left), None);
     }
 }
@@ -70,6 +70,58 @@ impl CubeVec {
     pub fn hex_neighbors(self) -> [Self; 6] {
         CubeDir::ALL.map(|v| self + v)
     }
+
+    /// The hex distance (number of steps) between this vector and `other`.
+    #[inline]
+    pub fn distance(self, other: Self) -> i32 {
+        let diff = self - other;
+        (diff.r.abs() + diff.q.abs() + diff.s.abs()) / 2
+    }
+
+    /// All cells within `radius` hex steps of this vector (inclusive), including itself.
+    pub fn range(self, radius: i32) -> impl Iterator<Item = Self> {
+        (-radius..=radius).flat_map(move |r| {
+            let q_min = (-radius - r).max(-radius);
+            let q_max = (-r + radius).min(radius);
+            (q_min..=q_max).map(move |q| self + Self::rq(r, q))
+        })
+    }
+
+    /// All cells at exactly `radius` hex steps from this vector.
+    pub fn ring(self, radius: i32) -> impl Iterator<Item = Self> {
+        let mut cells = Vec::new();
+        if radius == 0 {
+            cells.push(self);
+        } else {
+            let mut current = self + Self::from(CubeDir::UpLeft) * radius;
+            for dir in CubeDir::ALL {
+                for _ in 0..radius {
+                    cells.push(current);
+                    current += dir;
+                }
+            }
+        }
+        cells.into_iter()
+    }
+
+    /// All cells within `radius` hex steps of this vector, ordered ring by ring outward.
+    pub fn spiral(self, radius: i32) -> impl Iterator<Item = Self> {
+        std::iter::once(self).chain((1..=radius).flat_map(move |r| self.ring(r)))
+    }
+
+    /// Rotates this vector by `turns` around an arbitrary `center` instead of the origin.
+    pub fn rotated_around(self, center: Self, turns: i32) -> Self {
+        center + (self - center).rotated_by(turns)
+    }
+
+    /// Reflects this vector across the line through the origin along `axis`.
+    pub fn reflected_over(self, axis: CubeDir) -> Self {
+        match axis {
+            CubeDir::Right | CubeDir::Left => Self::new(-self.s, -self.q, -self.r),
+            CubeDir::DownRight | CubeDir::UpLeft => Self::new(-self.r, -self.s, -self.q),
+            CubeDir::UpRight | CubeDir::DownLeft => Self::new(-self.q, -self.r, -self.s),
+        }
+    }
 }
 
 impl Add for CubeVec {
@@ -229,7 +281,7 @@ impl TryFrom<&Element> for CubeVec {
 
 #[cfg(test)]
 mod tests {
-    use crate::{util::assert_xml_parse, game::CubeVec};
+    use crate::{util::assert_xml_parse, game::{CubeDir, CubeVec}};
 
     #[test]
     fn test_xml_parses() {
@@ -238,4 +290,66 @@ mod tests {
             CubeVec::new(23, 0, -2)
         );
     }
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(CubeVec::ZERO.distance(CubeVec::ZERO), 0);
+        assert_eq!(CubeVec::from(CubeDir::Right).distance(CubeVec::ZERO), 1);
+        assert_eq!(
+            CubeVec::from(CubeDir::Right).distance(CubeVec::from(CubeDir::Left)),
+            2
+        );
+    }
+
+    #[test]
+    fn test_range_yields_every_cell_up_to_the_radius() {
+        for radius in 0..=3 {
+            let cells: Vec<_> = CubeVec::ZERO.range(radius).collect();
+            assert_eq!(cells.len(), 1 + 3 * radius as usize * (radius as usize + 1));
+            assert!(cells.iter().all(|&cell| cell.distance(CubeVec::ZERO) <= radius));
+        }
+    }
+
+    #[test]
+    fn test_ring_yields_cells_at_exactly_the_radius() {
+        for radius in 1..=3 {
+            let cells: Vec<_> = CubeVec::ZERO.ring(radius).collect();
+            assert_eq!(cells.len(), 6 * radius as usize);
+            assert!(cells.iter().all(|&cell| cell.distance(CubeVec::ZERO) == radius));
+        }
+    }
+
+    #[test]
+    fn test_spiral_chains_rings_up_to_the_radius() {
+        let cells: Vec<_> = CubeVec::ZERO.spiral(2).collect();
+        assert_eq!(cells.len(), 1 + 6 + 12);
+        assert!(cells.contains(&CubeVec::ZERO));
+    }
+
+    #[test]
+    fn test_rotated_around_a_center() {
+        let center = CubeVec::from(CubeDir::Right);
+        let vec = center + CubeVec::from(CubeDir::UpRight);
+        assert_eq!(vec.rotated_around(center, 0), vec);
+        assert_eq!(
+            vec.rotated_around(center, 3),
+            center + CubeVec::from(CubeDir::DownLeft)
+        );
+    }
+
+    #[test]
+    fn test_reflected_over_fixes_its_own_axis() {
+        for axis in CubeDir::ALL {
+            let vec = CubeVec::from(axis);
+            assert_eq!(vec.reflected_over(axis), vec);
+        }
+    }
+
+    #[test]
+    fn test_reflected_over_mirrors_across_the_axis() {
+        assert_eq!(
+            CubeVec::from(CubeDir::UpRight).reflected_over(CubeDir::Right),
+            CubeVec::from(CubeDir::DownRight)
+        );
+    }
 }
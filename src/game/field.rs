@@ -0,0 +1,53 @@
+//! Ported from https://github.com/software-challenge/backend/blob/be88340f619892fe70c4cbd45e131d5445e883c7/plugin/src/main/kotlin/sc/plugin2024/Field.kt
+
+use crate::util::{Element, Error, Result};
+
+use super::CubeDir;
+
+/// A single field of the river board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Field {
+    /// Open, passable water.
+    Water,
+    /// An impassable island.
+    Island,
+    /// An impassable sandbank; ships that run aground here must push off before moving again.
+    Sandbank,
+    /// The finish line.
+    Goal,
+    /// A passenger waiting to be picked up, boarded by passing it on the given side.
+    Passenger(CubeDir),
+    /// A drifting log.
+    Log,
+}
+
+impl TryFrom<&Element> for Field {
+    type Error = Error;
+
+    fn try_from(elem: &Element) -> Result<Self> {
+        match elem.name() {
+            "WATER" => Ok(Self::Water),
+            "ISLAND" => Ok(Self::Island),
+            "SANDBANK" => Ok(Self::Sandbank),
+            "GOAL" => Ok(Self::Goal),
+            "PASSENGER" => Ok(Self::Passenger(elem.attribute("direction")?.parse()?)),
+            "LOG" => Ok(Self::Log),
+            other => Err(Error::UnknownVariant(format!("Unknown field type {other}"))),
+        }
+    }
+}
+
+impl From<Field> for Element {
+    fn from(field: Field) -> Self {
+        match field {
+            Field::Water => Element::new("WATER").build(),
+            Field::Island => Element::new("ISLAND").build(),
+            Field::Sandbank => Element::new("SANDBANK").build(),
+            Field::Goal => Element::new("GOAL").build(),
+            Field::Passenger(direction) => Element::new("PASSENGER")
+                .attribute("direction", direction)
+                .build(),
+            Field::Log => Element::new("LOG").build(),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+//! The Software Challenge 2024 ("Mississippi") game model: board, fields, ships and moves.
+
+mod action;
+mod board;
+mod cube_dir;
+mod cube_vec;
+mod field;
+#[allow(clippy::module_inception)]
+mod r#move;
+mod segment;
+mod ship_state;
+
+pub use action::Action;
+pub use board::Board;
+pub use cube_dir::CubeDir;
+pub use cube_vec::CubeVec;
+pub use field::Field;
+pub use r#move::Move;
+pub use segment::Segment;
+pub use ship_state::ShipState;
@@ -0,0 +1,50 @@
+//! Ported from https://github.com/software-challenge/backend/blob/be88340f619892fe70c4cbd45e131d5445e883c7/plugin/src/main/kotlin/sc/plugin2024/Segment.kt
+
+use crate::util::{Element, Error, Result};
+
+use super::{CubeDir, CubeVec, Field};
+
+/// One straight section of the river board, laid out on its own local field grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Segment {
+    direction: CubeDir,
+    center: CubeVec,
+    fields: Vec<Vec<Field>>,
+}
+
+impl Segment {
+    /// Creates a new segment with the given direction, absolute center and local field grid
+    /// (indexed `[column][row]`).
+    pub fn new(direction: CubeDir, center: CubeVec, fields: Vec<Vec<Field>>) -> Self {
+        Self { direction, center, fields }
+    }
+
+    /// The direction this segment advances towards the next one.
+    pub fn direction(&self) -> CubeDir {
+        self.direction
+    }
+
+    /// The absolute cube-coordinate center of this segment.
+    pub fn center(&self) -> CubeVec {
+        self.center
+    }
+
+    /// The local field grid, indexed `[column][row]`.
+    pub fn fields(&self) -> &Vec<Vec<Field>> {
+        &self.fields
+    }
+}
+
+impl TryFrom<&Element> for Segment {
+    type Error = Error;
+
+    fn try_from(elem: &Element) -> Result<Self> {
+        let direction = elem.attribute("direction")?.parse()?;
+        let center = CubeVec::try_from(elem.child_by_name("center")?)?;
+        let fields = elem.childs_by_name("field-array")
+            .map(|column| column.childs().map(Field::try_from).collect::<Result<Vec<Field>>>())
+            .collect::<Result<Vec<Vec<Field>>>>()?;
+
+        Ok(Self { direction, center, fields })
+    }
+}
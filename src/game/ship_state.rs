@@ -0,0 +1,10 @@
+use super::{CubeDir, CubeVec};
+
+/// The subset of a ship's state needed to enumerate its legal moves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShipState {
+    pub position: CubeVec,
+    pub direction: CubeDir,
+    pub speed: i32,
+    pub coal: i32,
+}
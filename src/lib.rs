@@ -0,0 +1,3 @@
+pub mod game;
+pub mod search;
+pub mod util;
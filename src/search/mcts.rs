@@ -0,0 +1,110 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{Duration, Instant};
+
+use crate::game::Move;
+
+use super::{GameState, SearchEngine};
+
+const EXPLORATION: f32 = std::f32::consts::SQRT_2;
+const MAX_ROLLOUT_DEPTH: u32 = 64;
+
+/// A node in the UCT search tree.
+struct Node<S> {
+    state: S,
+    m: Option<Move>,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    untried: Vec<Move>,
+    visits: u32,
+    value: f32,
+}
+
+impl<S: GameState> Node<S> {
+    fn new(state: S, m: Option<Move>, parent: Option<usize>) -> Self {
+        let untried = state.legal_moves();
+        Self { state, m, parent, children: Vec::new(), untried, visits: 0, value: 0.0 }
+    }
+
+    /// The UCT score of this node as seen from its parent.
+    fn uct(&self, parent_visits: u32) -> f32 {
+        if self.visits == 0 {
+            return f32::INFINITY;
+        }
+        // `value` is accumulated from this node's own player-to-move perspective, the opposite
+        // of the parent doing the selecting, so it must be negated here.
+        -self.value / self.visits as f32
+            + EXPLORATION * ((parent_visits as f32).ln() / self.visits as f32).sqrt()
+    }
+}
+
+/// A UCT Monte-Carlo Tree Search engine, bounded by a time budget.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Mcts;
+
+impl<S: GameState> SearchEngine<S> for Mcts {
+    fn choose_move(&self, state: &S, budget: Duration) -> Move {
+        let deadline = Instant::now() + budget;
+        let fallback = state.legal_moves().into_iter().next().expect("no legal moves available");
+        let mut nodes = vec![Node::new(state.clone(), None, None)];
+
+        while Instant::now() < deadline {
+            let mut current = 0;
+
+            // Selection: descend to a node with untried moves or no children.
+            while nodes[current].untried.is_empty() && !nodes[current].children.is_empty() {
+                let parent_visits = nodes[current].visits;
+                current = *nodes[current].children.iter()
+                    .max_by(|&&a, &&b| nodes[a].uct(parent_visits).total_cmp(&nodes[b].uct(parent_visits)))
+                    .expect("node has children");
+            }
+
+            // Expansion: pop one untried move.
+            if !nodes[current].untried.is_empty() {
+                let m = nodes[current].untried.pop().expect("untried move available");
+                let mut child_state = nodes[current].state.clone();
+                child_state.apply(&m);
+                let child = nodes.len();
+                nodes.push(Node::new(child_state, Some(m), Some(current)));
+                nodes[current].children.push(child);
+                current = child;
+            }
+
+            // Simulation: play random legal moves to a terminal state or depth cap.
+            let mut rollout = nodes[current].state.clone();
+            let mut depth = 0;
+            while !rollout.is_terminal() && depth < MAX_ROLLOUT_DEPTH {
+                let moves = rollout.legal_moves();
+                if moves.is_empty() {
+                    break;
+                }
+                rollout.apply(&moves[random_index(moves.len())]);
+                depth += 1;
+            }
+
+            // `evaluate()` is from the perspective of whoever is to move after `depth` rollout
+            // plies; fold that parity back to `current`'s own perspective before backpropagating.
+            let mut result = if depth % 2 == 0 { rollout.evaluate() } else { -rollout.evaluate() };
+
+            // Backpropagation: negate per ply for the opponent.
+            let mut node = Some(current);
+            while let Some(index) = node {
+                nodes[index].visits += 1;
+                nodes[index].value += result;
+                result = -result;
+                node = nodes[index].parent;
+            }
+        }
+
+        nodes[0].children.iter()
+            .max_by_key(|&&child| nodes[child].visits)
+            .and_then(|&child| nodes[child].m.clone())
+            .unwrap_or(fallback)
+    }
+}
+
+/// A pseudo-random index in `0..len`, seeded from the OS without pulling in a `rand` dependency.
+fn random_index(len: usize) -> usize {
+    let hash = RandomState::new().build_hasher().finish();
+    (hash as usize) % len
+}
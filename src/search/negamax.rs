@@ -0,0 +1,66 @@
+use std::time::{Duration, Instant};
+
+use super::{GameState, SearchEngine};
+
+/// An alpha-beta negamax searcher with iterative deepening, bounded by a time budget.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Negamax;
+
+impl<S: GameState> SearchEngine<S> for Negamax {
+    fn choose_move(&self, state: &S, budget: Duration) -> crate::game::Move {
+        let deadline = Instant::now() + budget;
+        let moves = state.legal_moves();
+        let mut best = moves.first().cloned().expect("no legal moves available");
+
+        let mut depth = 1;
+        while Instant::now() < deadline {
+            let mut best_score = f32::NEG_INFINITY;
+            let mut best_this_depth = best.clone();
+            let mut completed = true;
+
+            for m in &moves {
+                let mut next = state.clone();
+                next.apply(m);
+                let score = -negamax(&next, depth - 1, f32::NEG_INFINITY, f32::INFINITY, deadline);
+                if score > best_score {
+                    best_score = score;
+                    best_this_depth = m.clone();
+                }
+                if Instant::now() >= deadline {
+                    completed = false;
+                    break;
+                }
+            }
+
+            // Only adopt this depth's verdict once every root move has actually been compared;
+            // a deadline cutoff partway through must not discard the last completed depth's best.
+            if completed {
+                best = best_this_depth;
+            }
+
+            depth += 1;
+        }
+
+        best
+    }
+}
+
+fn negamax<S: GameState>(state: &S, depth: i32, mut alpha: f32, beta: f32, deadline: Instant) -> f32 {
+    if depth == 0 || state.is_terminal() || Instant::now() >= deadline {
+        return state.evaluate();
+    }
+
+    let mut best = f32::NEG_INFINITY;
+    for m in state.legal_moves() {
+        let mut next = state.clone();
+        next.apply(&m);
+        let score = -negamax(&next, depth - 1, -beta, -alpha, deadline);
+        best = best.max(score);
+        alpha = alpha.max(score);
+        if alpha >= beta {
+            break;
+        }
+    }
+
+    best
+}
@@ -0,0 +1,238 @@
+use std::fmt;
+
+use super::{Error, Result};
+
+/// A minimal in-memory XML element, used to (de)serialize the game protocol's wire format.
+///
+/// Elements are read through the inherent methods below (`attribute`, `child_by_name`,
+/// `childs_by_name`, `childs`) and built through [`ElementBuilder`], returned by [`Element::new`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Element {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<Element>,
+    text: String,
+}
+
+impl Element {
+    /// Starts building a new element with the given tag name.
+    #[allow(clippy::new_ret_no_self)] // `new` intentionally returns the builder, not `Self`
+    pub fn new(name: impl Into<String>) -> ElementBuilder {
+        ElementBuilder {
+            element: Element {
+                name: name.into(),
+                attributes: Vec::new(),
+                children: Vec::new(),
+                text: String::new(),
+            },
+        }
+    }
+
+    /// The tag name of this element.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The text content of this element, or an empty string if it has none.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Looks up a required attribute by name.
+    pub fn attribute(&self, name: &str) -> Result<&str> {
+        self.attributes.iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+            .ok_or_else(|| Error::MissingAttribute(name.to_string()))
+    }
+
+    /// Looks up the first child element with the given tag name.
+    pub fn child_by_name(&self, name: &str) -> Result<&Element> {
+        self.children.iter()
+            .find(|child| child.name == name)
+            .ok_or_else(|| Error::MissingChild(name.to_string()))
+    }
+
+    /// Iterates over every child element with the given tag name.
+    pub fn childs_by_name<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+
+    /// Iterates over every child element, regardless of tag name.
+    pub fn childs(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter()
+    }
+
+    /// Parses a single element from an XML string.
+    pub fn parse(xml: &str) -> Result<Self> {
+        let mut chars = xml.trim().chars().peekable();
+        let element = parse_element(&mut chars)?;
+        Ok(element)
+    }
+
+    fn write_indented(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        let indent = "    ".repeat(depth);
+        write!(f, "{indent}<{}", self.name)?;
+        for (key, value) in &self.attributes {
+            write!(f, " {key}=\"{value}\"")?;
+        }
+
+        if self.children.is_empty() && self.text.is_empty() {
+            writeln!(f, "/>")
+        } else if self.children.is_empty() {
+            writeln!(f, ">{}</{}>", self.text, self.name)
+        } else {
+            writeln!(f, ">")?;
+            for child in &self.children {
+                child.write_indented(f, depth + 1)?;
+            }
+            writeln!(f, "{indent}</{}>", self.name)
+        }
+    }
+}
+
+impl fmt::Display for Element {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_indented(f, 0)
+    }
+}
+
+/// A builder for an [`Element`], returned by [`Element::new`] and consumed by [`Self::build`].
+pub struct ElementBuilder {
+    element: Element,
+}
+
+impl ElementBuilder {
+    /// Sets an attribute, overwriting any previous value with the same key.
+    pub fn attribute(mut self, key: impl Into<String>, value: impl ToString) -> Self {
+        let key = key.into();
+        let value = value.to_string();
+        match self.element.attributes.iter_mut().find(|(existing, _)| *existing == key) {
+            Some(entry) => entry.1 = value,
+            None => self.element.attributes.push((key, value)),
+        }
+        self
+    }
+
+    /// Appends a single child element.
+    pub fn child(mut self, child: impl Into<Element>) -> Self {
+        self.element.children.push(child.into());
+        self
+    }
+
+    /// Appends every element yielded by `children`.
+    pub fn childs(mut self, children: impl IntoIterator<Item = Element>) -> Self {
+        self.element.children.extend(children);
+        self
+    }
+
+    /// Sets the text content of the element.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.element.text = text.into();
+        self
+    }
+
+    /// Finishes building, returning the constructed [`Element`].
+    pub fn build(self) -> Element {
+        self.element
+    }
+}
+
+impl From<ElementBuilder> for Element {
+    fn from(builder: ElementBuilder) -> Self {
+        builder.build()
+    }
+}
+
+fn parse_element(chars: &mut std::iter::Peekable<std::str::Chars>) -> Result<Element> {
+    skip_whitespace(chars);
+    expect(chars, '<')?;
+    let name = read_name(chars);
+
+    let mut element = Element { name: name.clone(), attributes: Vec::new(), children: Vec::new(), text: String::new() };
+
+    loop {
+        skip_whitespace(chars);
+        match chars.peek() {
+            Some('/') => {
+                chars.next();
+                expect(chars, '>')?;
+                return Ok(element);
+            }
+            Some('>') => {
+                chars.next();
+                break;
+            }
+            Some(_) => {
+                let key = read_name(chars);
+                skip_whitespace(chars);
+                expect(chars, '=')?;
+                skip_whitespace(chars);
+                expect(chars, '"')?;
+                let value = read_until(chars, '"');
+                expect(chars, '"')?;
+                element.attributes.push((key, value));
+            }
+            None => return Err(Error::MalformedXml(format!("unexpected end of input in <{name}>"))),
+        }
+    }
+
+    loop {
+        skip_whitespace(chars);
+        let is_open_tag = chars.peek() == Some(&'<');
+        match (is_open_tag, is_open_tag && peek_closing_tag(chars)) {
+            (_, true) => {
+                chars.next();
+                chars.next();
+                let closing = read_name(chars);
+                if closing != name {
+                    return Err(Error::MalformedXml(format!("expected </{name}>, found </{closing}>")));
+                }
+                skip_whitespace(chars);
+                expect(chars, '>')?;
+                return Ok(element);
+            }
+            (true, false) => {
+                element.children.push(parse_element(chars)?);
+            }
+            (false, _) if chars.peek().is_some() => {
+                element.text.push_str(&read_until(chars, '<'));
+            }
+            _ => return Err(Error::MalformedXml(format!("unexpected end of input in <{name}>"))),
+        }
+    }
+}
+
+fn peek_closing_tag(chars: &std::iter::Peekable<std::str::Chars>) -> bool {
+    let mut clone = chars.clone();
+    clone.next() == Some('<') && clone.next() == Some('/')
+}
+
+fn skip_whitespace(chars: &mut std::iter::Peekable<std::str::Chars>) {
+    while matches!(chars.peek(), Some(c) if c.is_whitespace()) {
+        chars.next();
+    }
+}
+
+fn expect(chars: &mut std::iter::Peekable<std::str::Chars>, expected: char) -> Result<()> {
+    match chars.next() {
+        Some(c) if c == expected => Ok(()),
+        other => Err(Error::MalformedXml(format!("expected '{expected}', found {other:?}"))),
+    }
+}
+
+fn read_name(chars: &mut std::iter::Peekable<std::str::Chars>) -> String {
+    let mut name = String::new();
+    while matches!(chars.peek(), Some(c) if c.is_alphanumeric() || *c == '_' || *c == '-' || *c == ':') {
+        name.push(chars.next().expect("peeked"));
+    }
+    name
+}
+
+fn read_until(chars: &mut std::iter::Peekable<std::str::Chars>, delimiter: char) -> String {
+    let mut text = String::new();
+    while matches!(chars.peek(), Some(c) if *c != delimiter) {
+        text.push(chars.next().expect("peeked"));
+    }
+    text
+}
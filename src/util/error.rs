@@ -0,0 +1,45 @@
+use std::fmt;
+
+/// Errors produced while parsing or converting the XML protocol types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// An element was missing a required attribute.
+    MissingAttribute(String),
+    /// An element was missing a required child element.
+    MissingChild(String),
+    /// The XML text could not be parsed at all.
+    MalformedXml(String),
+    /// An attribute or tag held a value outside the set of known variants.
+    UnknownVariant(String),
+    /// An attribute value could not be parsed as the expected primitive type.
+    ParseValue(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingAttribute(name) => write!(f, "missing attribute `{name}`"),
+            Error::MissingChild(name) => write!(f, "missing child element `{name}`"),
+            Error::MalformedXml(reason) => write!(f, "malformed XML: {reason}"),
+            Error::UnknownVariant(reason) => write!(f, "unknown variant: {reason}"),
+            Error::ParseValue(reason) => write!(f, "could not parse value: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::num::ParseIntError> for Error {
+    fn from(err: std::num::ParseIntError) -> Self {
+        Error::ParseValue(err.to_string())
+    }
+}
+
+impl From<std::num::ParseFloatError> for Error {
+    fn from(err: std::num::ParseFloatError) -> Self {
+        Error::ParseValue(err.to_string())
+    }
+}
+
+/// The result type used throughout the XML protocol layer.
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,62 @@
+//! Small, dependency-free building blocks shared across the crate: the [`Element`] XML tree used
+//! as the wire format, its [`Error`]/[`Result`], the [`Vec2`] local-coordinate helper, and the
+//! [`Perform`] trait.
+
+mod element;
+mod error;
+mod perform;
+mod vec2;
+
+pub use element::{Element, ElementBuilder};
+pub use error::{Error, Result};
+pub use perform::Perform;
+pub use vec2::Vec2;
+
+/// Converts `element` via `TryFrom<&Element>`, inferring the target type from `hint`.
+///
+/// A plain `TryFrom::try_from(&element)` call leaves the `Self` type fully unconstrained until
+/// the caller's next statement, which rustc refuses to resolve; threading `hint` through lets
+/// inference pin it down right here instead.
+#[doc(hidden)]
+pub fn try_from_hinted<'a, T>(element: &'a Element, _hint: &T) -> T
+where
+    T: TryFrom<&'a Element>,
+    T::Error: std::fmt::Debug,
+{
+    T::try_from(element).expect("failed to convert element")
+}
+
+/// Parses `$xml`, converts it via `TryFrom<&Element>`, and asserts it equals `$expected`.
+#[macro_export]
+macro_rules! assert_xml_parse {
+    ($xml:expr, $expected:expr) => {{
+        let expected = $expected;
+        let element = $crate::util::Element::parse($xml).expect("failed to parse XML");
+        let actual = $crate::util::try_from_hinted(&element, &expected);
+        assert_eq!(actual, expected);
+    }};
+}
+
+/// Converts `$value` via `Into<Element>` and asserts its formatted XML equals `$expected`.
+#[macro_export]
+macro_rules! assert_xml_format {
+    ($value:expr, $expected:expr) => {{
+        let element: $crate::util::Element = $value.into();
+        assert_eq!(element.to_string(), $expected);
+    }};
+}
+
+/// Asserts that converting `$value` to an [`Element`] and back via `TryFrom` reproduces it.
+#[macro_export]
+macro_rules! assert_xml_roundtrip {
+    ($value:expr) => {{
+        let value = $value;
+        let element: $crate::util::Element = value.clone().into();
+        let roundtripped = $crate::util::try_from_hinted(&element, &value);
+        assert_eq!(value, roundtripped);
+    }};
+}
+
+pub use crate::assert_xml_format;
+pub use crate::assert_xml_parse;
+pub use crate::assert_xml_roundtrip;
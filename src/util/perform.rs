@@ -0,0 +1,9 @@
+/// Something that `T` can be applied to, e.g. applying a single [`Action`](crate::game::Action)
+/// or a whole [`Move`](crate::game::Move) to a game state.
+pub trait Perform<T> {
+    /// The result of applying `value`.
+    type Output;
+
+    /// Applies `value` to `self`.
+    fn perform(&mut self, value: T) -> Self::Output;
+}
@@ -0,0 +1,14 @@
+/// A generic 2D local-coordinate vector, used to index a [`Segment`](crate::game::Segment)'s
+/// field grid before it is folded into cube coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Vec2<T> {
+    /// Creates a new vector from the given components.
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}